@@ -0,0 +1,205 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+
+use crate::{
+    error::AppError,
+    model::{BookResponse, CreateBook, ListBooksFilter, ListBooksQuery, Paginated, UpdateBook},
+    repository::BookRepository,
+    skill::{self, SkillRequest, SkillResponse},
+    timezone::RequestTz,
+};
+
+// チャットボットの検索スキルで返す最大件数
+const SKILL_SEARCH_LIMIT: i64 = 5;
+
+// リポジトリをトレイトオブジェクトとしてExtensionに載せるための型エイリアス
+pub type Repo = Arc<dyn BookRepository>;
+
+// No Content（204）のみを返す関数
+// IntoResponseトレイトが実装された型であればどんな型でも返せる
+pub async fn health_check() -> impl IntoResponse {
+    StatusCode::NO_CONTENT
+}
+
+// 書籍のリストを取得するAPIの実装
+// limit/offsetでのページングに加え、author/title/statusでの絞り込みに対応する
+pub async fn book_list(
+    Extension(repo): Extension<Repo>,
+    Query(query): Query<ListBooksQuery>,
+    RequestTz(tz): RequestTz,
+) -> Result<Json<Paginated<BookResponse>>, AppError> {
+    let filter = ListBooksFilter::try_from(query)?;
+    let limit = filter.limit;
+    let offset = filter.offset;
+    let (items, total) = repo.list(filter).await?;
+
+    Ok(Json(Paginated {
+        items: items
+            .into_iter()
+            .map(|book| book.into_response(tz))
+            .collect(),
+        total,
+        limit,
+        offset,
+    }))
+}
+
+// 書籍を1件登録するAPIの実装
+pub async fn book_create(
+    Extension(repo): Extension<Repo>,
+    RequestTz(tz): RequestTz,
+    Json(payload): Json<CreateBook>,
+) -> Result<Json<BookResponse>, AppError> {
+    let book = repo.create(payload).await?;
+    Ok(Json(book.into_response(tz)))
+}
+
+// 書籍を1件更新するAPIの実装（読書状況の変更を含む）
+pub async fn book_update(
+    Extension(repo): Extension<Repo>,
+    Path(id): Path<i64>,
+    RequestTz(tz): RequestTz,
+    Json(payload): Json<UpdateBook>,
+) -> Result<Json<BookResponse>, AppError> {
+    let book = repo.update(id, payload).await?;
+    Ok(Json(book.into_response(tz)))
+}
+
+// 書籍を1件削除するAPIの実装
+pub async fn book_delete(
+    Extension(repo): Extension<Repo>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    repo.delete(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// チャットボットプラットフォーム向けのスキルサーバー実装
+// 発話に含まれるキーワードで書籍を検索し、ListCard（ヒット無しの場合はSimpleText）で返す
+//
+// ボットプラットフォームはWebhookのレスポンスが常にスキルのJSON形式であることを期待するため、
+// 検索に失敗した場合もAppErrorのエラーボディではなくヒット無し扱いのSimpleTextにフォールバックする
+pub async fn skill_books(
+    Extension(repo): Extension<Repo>,
+    Json(payload): Json<SkillRequest>,
+) -> Json<SkillResponse> {
+    let keyword = payload.user_request.utterance;
+    let books = repo
+        .search(&keyword, SKILL_SEARCH_LIMIT)
+        .await
+        .unwrap_or_else(|err| {
+            tracing::error!(?err, "failed to search books for skill request");
+            Vec::new()
+        });
+
+    Json(skill::build_response(&keyword, books))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use chrono_tz::Tz;
+
+    use super::*;
+    use crate::model::Book;
+
+    // BookRepositoryのモック実装
+    // ハンドラーがsqlxを直接呼ばず、トレイト越しにしか永続化を扱わないことを利用して、
+    // DBに接続せずにcontrollerの組み立て（フィルタ生成・レスポンス整形）だけを検証する
+    struct MockBookRepository {
+        books: Vec<Book>,
+    }
+
+    #[async_trait::async_trait]
+    impl BookRepository for MockBookRepository {
+        async fn list(
+            &self,
+            _filter: crate::model::ListBooksFilter,
+        ) -> Result<(Vec<Book>, i64), AppError> {
+            Ok((self.books.clone(), self.books.len() as i64))
+        }
+
+        async fn find(&self, id: i64) -> Result<Book, AppError> {
+            self.books
+                .iter()
+                .find(|book| book.id == id)
+                .cloned()
+                .ok_or(AppError::NotFound)
+        }
+
+        async fn create(&self, _book: CreateBook) -> Result<Book, AppError> {
+            unimplemented!("not exercised by these tests")
+        }
+
+        async fn update(&self, _id: i64, _book: UpdateBook) -> Result<Book, AppError> {
+            unimplemented!("not exercised by these tests")
+        }
+
+        async fn delete(&self, id: i64) -> Result<(), AppError> {
+            if self.books.iter().any(|book| book.id == id) {
+                Ok(())
+            } else {
+                Err(AppError::NotFound)
+            }
+        }
+
+        async fn search(&self, _keyword: &str, _limit: i64) -> Result<Vec<Book>, AppError> {
+            unimplemented!("not exercised by these tests")
+        }
+    }
+
+    fn sample_book(id: i64) -> Book {
+        let timestamp =
+            NaiveDateTime::parse_from_str("2024-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        Book {
+            id,
+            title: "Rust入門".to_string(),
+            author: "テスト太郎".to_string(),
+            publisher: "テスト出版".to_string(),
+            isbn: "0000000000000".to_string(),
+            comment: String::new(),
+            status: crate::model::BookStatus::ToRead,
+            created_at: timestamp,
+            updated_at: timestamp,
+        }
+    }
+
+    #[tokio::test]
+    async fn book_list_returns_items_and_total_from_the_repository() {
+        let repo: Repo = Arc::new(MockBookRepository {
+            books: vec![sample_book(1), sample_book(2)],
+        });
+        let query = ListBooksQuery {
+            limit: None,
+            offset: None,
+            author: None,
+            title: None,
+            status: None,
+        };
+
+        let response = book_list(Extension(repo), Query(query), RequestTz(Tz::UTC))
+            .await
+            .expect("book_list should succeed");
+
+        assert_eq!(response.0.total, 2);
+        assert_eq!(response.0.items.len(), 2);
+        assert_eq!(response.0.items[0].id, 1);
+    }
+
+    #[tokio::test]
+    async fn book_delete_returns_not_found_for_a_missing_book() {
+        let repo: Repo = Arc::new(MockBookRepository { books: vec![] });
+
+        let err = book_delete(Extension(repo), Path(1))
+            .await
+            .expect_err("deleting a missing book should fail");
+
+        assert!(matches!(err, AppError::NotFound));
+    }
+}
@@ -0,0 +1,49 @@
+use std::env;
+
+use axum::http::{header::CONTENT_TYPE, HeaderValue, Method};
+use tower_http::cors::CorsLayer;
+
+/**
+ * CORSの許可Origin/メソッドを環境変数から組み立てる
+ * `CORS_ALLOWED_ORIGINS`（カンマ区切り）が設定されていればそれだけを許可し、
+ * `CORS_ALLOWED_METHODS`（同じくカンマ区切り、未設定時はGET/POST/PUT/DELETE）も併せて絞り込む。
+ * `CORS_ALLOWED_ORIGINS`が未設定、または有効なOriginを1つも含まない場合は、開発時の挙動として
+ * `CORS_ALLOWED_METHODS`の値にかかわらず全許可（permissive）にフォールバックする。
+ */
+pub fn build() -> CorsLayer {
+    let origins: Vec<HeaderValue> = env::var("CORS_ALLOWED_ORIGINS")
+        .ok()
+        .map(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|origin| !origin.is_empty())
+                .filter_map(|origin| origin.parse().ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if origins.is_empty() {
+        return CorsLayer::permissive();
+    }
+
+    let methods: Vec<Method> = env::var("CORS_ALLOWED_METHODS")
+        .ok()
+        .map(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|method| !method.is_empty())
+                .filter_map(|method| method.parse().ok())
+                .collect()
+        })
+        .filter(|methods: &Vec<Method>| !methods.is_empty())
+        .unwrap_or_else(|| vec![Method::GET, Method::POST, Method::PUT, Method::DELETE]);
+
+    // CreateBook/UpdateBookはJSONボディで送られてくるため、Content-Typeをプリフライトで許可しておかないと
+    // ブラウザからのPOST/PUT /booksがCORSで弾かれてしまう
+    CorsLayer::new()
+        .allow_origin(origins)
+        .allow_methods(methods)
+        .allow_headers([CONTENT_TYPE])
+}
@@ -0,0 +1,17 @@
+use std::env;
+
+use sqlx::MySqlPool;
+
+/**
+ * dotenvyで.envに書いたDATABASE_URLを読み込み、MySQLへのコネクションプールを確立する
+ * mainから呼び出されるエントリーポイントとしてこのモジュールに切り出した
+ */
+pub async fn conn() -> MySqlPool {
+    dotenvy::dotenv().ok();
+
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+
+    MySqlPool::connect(&database_url)
+        .await
+        .expect("failed to connect to database")
+}
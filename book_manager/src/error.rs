@@ -0,0 +1,49 @@
+use axum::{http::StatusCode, response::IntoResponse, Json};
+use serde::Serialize;
+use thiserror::Error;
+
+/**
+ * アプリケーション全体で共通して扱うエラー型
+ * ハンドラーの戻り値をResult<T, AppError>にすることで、
+ * `?`演算子でsqlx::Errorなどを素通しでき、変換と分類をこの型に集約できる
+ */
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("resource not found")]
+    NotFound,
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("validation error: {0}")]
+    Validation(String),
+}
+
+// クライアントに返すエラーのJSON表現
+#[derive(Serialize)]
+struct ErrorBody {
+    error: &'static str,
+    message: String,
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> axum::response::Response {
+        let (status, error) = match &self {
+            AppError::NotFound => (StatusCode::NOT_FOUND, "not_found"),
+            AppError::Database(sqlx::Error::RowNotFound) => (StatusCode::NOT_FOUND, "not_found"),
+            AppError::Database(_) => (StatusCode::INTERNAL_SERVER_ERROR, "database_error"),
+            AppError::Validation(_) => (StatusCode::BAD_REQUEST, "validation_error"),
+        };
+
+        // Database(_)の生のsqlx::Errorにはドライバーやクエリーの詳細が含まれ得るため、
+        // クライアントには返さずサーバー側のログにだけ残し、レスポンスには一般的な文言を使う
+        let message = match &self {
+            AppError::Database(sqlx::Error::RowNotFound) => self.to_string(),
+            AppError::Database(err) => {
+                tracing::error!(error = ?err, "database error while handling request");
+                "internal server error".to_string()
+            }
+            _ => self.to_string(),
+        };
+
+        (status, Json(ErrorBody { error, message })).into_response()
+    }
+}
@@ -0,0 +1,212 @@
+use chrono::NaiveDateTime;
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::AppError, timezone};
+
+/**
+ * 書籍の読書状況を表す列挙型
+ * MySQL側ではvarchar カラムとして保持する。
+ * `#[derive(sqlx::Type)]`はMySQLバックエンドではネイティブの`ENUM(...)`列を前提にした
+ * `type_info`/`compatible`しか生成できず、varchar列とは実行時に型不一致になってしまうため、
+ * ここでは`Type`/`Encode`/`Decode`を文字列ベースで手書きしている。
+ */
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BookStatus {
+    ToRead,
+    Reading,
+    Finished,
+    Rereading,
+}
+
+impl BookStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            BookStatus::ToRead => "to_read",
+            BookStatus::Reading => "reading",
+            BookStatus::Finished => "finished",
+            BookStatus::Rereading => "rereading",
+        }
+    }
+
+    fn from_str(value: &str) -> Result<Self, String> {
+        match value {
+            "to_read" => Ok(BookStatus::ToRead),
+            "reading" => Ok(BookStatus::Reading),
+            "finished" => Ok(BookStatus::Finished),
+            "rereading" => Ok(BookStatus::Rereading),
+            other => Err(format!("unknown book status: {other}")),
+        }
+    }
+}
+
+impl sqlx::Type<sqlx::MySql> for BookStatus {
+    fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+        <&str as sqlx::Type<sqlx::MySql>>::type_info()
+    }
+
+    fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+        <&str as sqlx::Type<sqlx::MySql>>::compatible(ty)
+    }
+}
+
+impl<'q> sqlx::Encode<'q, sqlx::MySql> for BookStatus {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::MySql as sqlx::database::HasArguments<'q>>::ArgumentBuffer,
+    ) -> sqlx::encode::IsNull {
+        <&str as sqlx::Encode<'q, sqlx::MySql>>::encode_by_ref(&self.as_str(), buf)
+    }
+}
+
+impl<'r> sqlx::Decode<'r, sqlx::MySql> for BookStatus {
+    fn decode(
+        value: <sqlx::MySql as sqlx::database::HasValueRef<'r>>::ValueRef,
+    ) -> Result<Self, sqlx::error::BoxDynError> {
+        let raw = <&str as sqlx::Decode<'r, sqlx::MySql>>::decode(value)?;
+        BookStatus::from_str(raw).map_err(Into::into)
+    }
+}
+
+/**
+ * 書籍情報を表す構造体
+ * データベースに問い合わせた結果のデータを格納するのに使用
+ * Bookには #[derive(Serialize)] というアトリビュートが付与されている。
+ * これがserdeクレートによるアトリビュートだ。
+ * これを付与することで、Rustの構造体をJSON形式に変換する実装を自動で導出できる。
+ */
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct Book {
+    pub id: i64,
+    pub title: String,
+    pub author: String,
+    pub publisher: String,
+    pub isbn: String,
+    pub comment: String,
+    pub status: BookStatus,
+    // NativeDateTimeはchronoクレートが提供する型
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/**
+ * created_at/updated_atはDBにはUTC基準のNaiveDateTimeで保存されているが、
+ * そのままでは「どの瞬間を指すのか」がJSON上で分からない。
+ * レスポンスに使う際はBookResponseに変換し、リクエストで指定されたタイムゾーンの
+ * RFC3339文字列（オフセット付き）にしてから返す。
+ */
+#[derive(Serialize)]
+pub struct BookResponse {
+    pub id: i64,
+    pub title: String,
+    pub author: String,
+    pub publisher: String,
+    pub isbn: String,
+    pub comment: String,
+    pub status: BookStatus,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl Book {
+    pub fn into_response(self, tz: Tz) -> BookResponse {
+        BookResponse {
+            id: self.id,
+            title: self.title,
+            author: self.author,
+            publisher: self.publisher,
+            isbn: self.isbn,
+            comment: self.comment,
+            status: self.status,
+            created_at: timezone::to_rfc3339(self.created_at, tz),
+            updated_at: timezone::to_rfc3339(self.updated_at, tz),
+        }
+    }
+}
+
+// GET /books のクエリーパラメーター
+// limit/offsetに加えて、著者・タイトルの部分一致と読書状況での絞り込みに対応する
+#[derive(Deserialize)]
+pub struct ListBooksQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub author: Option<String>,
+    pub title: Option<String>,
+    pub status: Option<BookStatus>,
+}
+
+// リポジトリに渡す検索条件（limit/offsetはデフォルト値で補完済み）
+pub struct ListBooksFilter {
+    pub limit: i64,
+    pub offset: i64,
+    pub author: Option<String>,
+    pub title: Option<String>,
+    pub status: Option<BookStatus>,
+}
+
+pub const DEFAULT_LIMIT: i64 = 20;
+
+impl TryFrom<ListBooksQuery> for ListBooksFilter {
+    type Error = AppError;
+
+    fn try_from(query: ListBooksQuery) -> Result<Self, Self::Error> {
+        let limit = query.limit.unwrap_or(DEFAULT_LIMIT);
+        if limit < 0 {
+            return Err(AppError::Validation(
+                "limit must not be negative".to_string(),
+            ));
+        }
+
+        let offset = query.offset.unwrap_or(0);
+        if offset < 0 {
+            return Err(AppError::Validation(
+                "offset must not be negative".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            limit,
+            offset,
+            author: query.author,
+            title: query.title,
+            status: query.status,
+        })
+    }
+}
+
+// ページングされたレスポンスの共通エンベロープ
+#[derive(Serialize)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+// POST /books のリクエストボディ
+#[derive(Deserialize)]
+pub struct CreateBook {
+    pub title: String,
+    pub author: String,
+    pub publisher: String,
+    pub isbn: String,
+    pub comment: String,
+    #[serde(default = "default_status")]
+    pub status: BookStatus,
+}
+
+fn default_status() -> BookStatus {
+    BookStatus::ToRead
+}
+
+// PUT /books/:id のリクエストボディ
+#[derive(Deserialize)]
+pub struct UpdateBook {
+    pub title: String,
+    pub author: String,
+    pub publisher: String,
+    pub isbn: String,
+    pub comment: String,
+    pub status: BookStatus,
+}
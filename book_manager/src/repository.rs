@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use sqlx::{MySql, MySqlPool, QueryBuilder};
+
+use crate::{
+    error::AppError,
+    model::{Book, BookStatus, CreateBook, ListBooksFilter, UpdateBook},
+};
+
+/**
+ * 書籍データの永続化を担うリポジトリ
+ * ハンドラー（controller）がsqlxを直接呼ぶのではなく、このトレイト越しに永続化を扱うことで、
+ * HTTPの関心事と永続化の関心事を分離し、モックを差し替えた単体テストも書きやすくする
+ */
+#[async_trait]
+pub trait BookRepository: Send + Sync {
+    async fn list(&self, filter: ListBooksFilter) -> Result<(Vec<Book>, i64), AppError>;
+    async fn find(&self, id: i64) -> Result<Book, AppError>;
+    async fn create(&self, book: CreateBook) -> Result<Book, AppError>;
+    async fn update(&self, id: i64, book: UpdateBook) -> Result<Book, AppError>;
+    async fn delete(&self, id: i64) -> Result<(), AppError>;
+    async fn search(&self, keyword: &str, limit: i64) -> Result<Vec<Book>, AppError>;
+}
+
+// sqlxのMySqlPoolを利用したBookRepositoryの実装
+pub struct MySqlBookRepository {
+    pool: MySqlPool,
+}
+
+impl MySqlBookRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BookRepository for MySqlBookRepository {
+    async fn list(&self, filter: ListBooksFilter) -> Result<(Vec<Book>, i64), AppError> {
+        // limit/offsetとauthor/title/statusのフィルタはリクエストごとに有無が変わるため、
+        // query_as!マクロ（コンパイル時にクエリーを固定する）ではなくQueryBuilderで動的に組み立てる
+        let mut count_builder: QueryBuilder<MySql> =
+            QueryBuilder::new("select count(*) as total from books");
+        push_where(&mut count_builder, &filter);
+        let total: i64 = count_builder
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let mut select_builder: QueryBuilder<MySql> = QueryBuilder::new(
+            r#"select id, title, author, publisher, isbn, comment, status, created_at, updated_at from books"#,
+        );
+        push_where(&mut select_builder, &filter);
+        select_builder
+            .push(" order by id limit ")
+            .push_bind(filter.limit)
+            .push(" offset ")
+            .push_bind(filter.offset);
+
+        let books = select_builder
+            .build_query_as::<Book>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok((books, total))
+    }
+
+    async fn find(&self, id: i64) -> Result<Book, AppError> {
+        let book = sqlx::query_as!(
+            Book,
+            r#"select id, title, author, publisher, isbn, comment, status as "status: BookStatus", created_at, updated_at from books where id = ?"#,
+            id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(book)
+    }
+
+    async fn create(&self, book: CreateBook) -> Result<Book, AppError> {
+        let result = sqlx::query!(
+            "insert into books (title, author, publisher, isbn, comment, status) values (?, ?, ?, ?, ?, ?)",
+            book.title,
+            book.author,
+            book.publisher,
+            book.isbn,
+            book.comment,
+            book.status,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        self.find(result.last_insert_id() as i64).await
+    }
+
+    async fn update(&self, id: i64, book: UpdateBook) -> Result<Book, AppError> {
+        // MySQLのUPDATEはCLIENT_FOUND_ROWSを有効にしない限りrows_affectedが
+        // 「マッチした行数」ではなく「実際に値が変わった行数」を返す。そのため、
+        // 同じ内容での再送（タイムアウト後のリトライ等）はrows_affected == 0になり得る。
+        // 行の有無はfindで別途確認し、rows_affectedからは推測しない。
+        self.find(id).await?;
+
+        sqlx::query!(
+            "update books set title = ?, author = ?, publisher = ?, isbn = ?, comment = ?, status = ? where id = ?",
+            book.title,
+            book.author,
+            book.publisher,
+            book.isbn,
+            book.comment,
+            book.status,
+            id,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        self.find(id).await
+    }
+
+    async fn delete(&self, id: i64) -> Result<(), AppError> {
+        let result = sqlx::query!("delete from books where id = ?", id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    // タイトルまたは著者がキーワードに部分一致する書籍を最大limit件検索する
+    // スキルサーバー（チャットボット）からの検索で利用する
+    async fn search(&self, keyword: &str, limit: i64) -> Result<Vec<Book>, AppError> {
+        let pattern = format!("%{keyword}%");
+        let books = sqlx::query_as!(
+            Book,
+            r#"select id, title, author, publisher, isbn, comment, status as "status: BookStatus", created_at, updated_at from books where title like ? or author like ? order by id limit ?"#,
+            pattern,
+            pattern,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(books)
+    }
+}
+
+// author/title/statusの各フィルタが指定されているときだけWHERE句に条件を足していく
+fn push_where<'a>(builder: &mut QueryBuilder<'a, MySql>, filter: &'a ListBooksFilter) {
+    let mut conditions = Vec::new();
+    if let Some(author) = &filter.author {
+        conditions.push(("author", author));
+    }
+    if let Some(title) = &filter.title {
+        conditions.push(("title", title));
+    }
+
+    let mut has_condition = false;
+    for (column, value) in conditions {
+        builder.push(if has_condition { " and " } else { " where " });
+        builder.push(format!("{column} like "));
+        builder.push_bind(format!("%{value}%"));
+        has_condition = true;
+    }
+
+    if let Some(status) = filter.status {
+        builder.push(if has_condition { " and " } else { " where " });
+        builder.push("status = ");
+        builder.push_bind(status);
+    }
+}
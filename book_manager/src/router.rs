@@ -0,0 +1,28 @@
+use axum::{
+    routing::{get, post, put},
+    Router,
+};
+
+use crate::controller;
+
+/**
+ * /booksを起点にネストしたルーターを組み立てる
+ * controllerに定義したハンドラーの登録だけをこのモジュールの責務とし、
+ * 永続化やHTTPのレイヤー設定（main側）とは分離する
+ */
+pub fn build() -> Router {
+    let books_router = Router::new()
+        .route(
+            "/",
+            get(controller::book_list).post(controller::book_create),
+        )
+        .route(
+            "/:id",
+            put(controller::book_update).delete(controller::book_delete),
+        );
+
+    Router::new()
+        .route("/health", get(controller::health_check))
+        .nest("/books", books_router)
+        .route("/skill/books", post(controller::skill_books))
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::Book;
+
+// カカオ等のチャットボットプラットフォームから届くWebhookのリクエストボディ
+// 利用者が入力した発話（utterance）だけをこのサーバーでは使う
+#[derive(Deserialize)]
+pub struct SkillRequest {
+    #[serde(rename = "userRequest")]
+    pub user_request: UserRequest,
+}
+
+#[derive(Deserialize)]
+pub struct UserRequest {
+    pub utterance: String,
+}
+
+// 検索ヒット件数に応じてListCardかSimpleTextのどちらかを返す
+#[derive(Serialize)]
+pub struct SkillResponse {
+    pub version: &'static str,
+    pub template: Template,
+}
+
+#[derive(Serialize)]
+pub struct Template {
+    pub outputs: Vec<SkillOutput>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum SkillOutput {
+    ListCard {
+        #[serde(rename = "listCard")]
+        list_card: ListCard,
+    },
+    SimpleText {
+        #[serde(rename = "simpleText")]
+        simple_text: SimpleText,
+    },
+}
+
+#[derive(Serialize)]
+pub struct ListCard {
+    pub header: ListCardHeader,
+    pub items: Vec<ListCardItem>,
+    pub buttons: Vec<serde_json::Value>,
+}
+
+#[derive(Serialize)]
+pub struct ListCardHeader {
+    pub title: String,
+}
+
+#[derive(Serialize)]
+pub struct ListCardItem {
+    pub title: String,
+    pub description: String,
+}
+
+#[derive(Serialize)]
+pub struct SimpleText {
+    pub text: String,
+}
+
+const NOT_FOUND_MESSAGE: &str = "該当する書籍がありません";
+
+// 検索結果をスキルサーバーのレスポンス形式に組み立てる
+// ヒットが0件の場合はSimpleTextで案内し、1件以上あればListCardで一覧を返す
+pub fn build_response(keyword: &str, books: Vec<Book>) -> SkillResponse {
+    let output = if books.is_empty() {
+        SkillOutput::SimpleText {
+            simple_text: SimpleText {
+                text: NOT_FOUND_MESSAGE.to_string(),
+            },
+        }
+    } else {
+        SkillOutput::ListCard {
+            list_card: ListCard {
+                header: ListCardHeader {
+                    title: format!("「{keyword}」の検索結果"),
+                },
+                items: books
+                    .into_iter()
+                    .map(|book| ListCardItem {
+                        title: book.title,
+                        description: format!("{} ・ {}", book.author, book.publisher),
+                    })
+                    .collect(),
+                buttons: Vec::new(),
+            },
+        }
+    };
+
+    SkillResponse {
+        version: "2.0",
+        template: Template {
+            outputs: vec![output],
+        },
+    }
+}
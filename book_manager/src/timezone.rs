@@ -0,0 +1,54 @@
+use axum::{
+    extract::{FromRequestParts, Query},
+    http::request::Parts,
+};
+use chrono::{NaiveDateTime, TimeZone as _, Utc};
+use chrono_tz::Tz;
+use serde::Deserialize;
+
+// ?tz=Asia/Tokyo のようなクエリーパラメーターを受け取るための構造体
+#[derive(Deserialize)]
+struct TzQuery {
+    tz: Option<String>,
+}
+
+/**
+ * レスポンスのタイムゾーンを表す抽出器
+ * `Accept-Timezone`ヘッダー、なければ`?tz=`クエリーパラメーターを見て変換先のタイムゾーンを決める。
+ * どちらも無い、あるいは解釈できない値だった場合はUTCにフォールバックする。
+ */
+pub struct RequestTz(pub Tz);
+
+#[axum::async_trait]
+impl<S> FromRequestParts<S> for RequestTz
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        if let Some(tz) = parts
+            .headers
+            .get("Accept-Timezone")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<Tz>().ok())
+        {
+            return Ok(RequestTz(tz));
+        }
+
+        if let Ok(Query(query)) = Query::<TzQuery>::from_request_parts(parts, state).await {
+            if let Some(tz) = query.tz.and_then(|value| value.parse::<Tz>().ok()) {
+                return Ok(RequestTz(tz));
+            }
+        }
+
+        Ok(RequestTz(Tz::UTC))
+    }
+}
+
+// DBに保存されたUTC基準のNaiveDateTimeを指定タイムゾーンのRFC3339文字列に変換する
+pub fn to_rfc3339(naive: NaiveDateTime, tz: Tz) -> String {
+    Utc.from_utc_datetime(&naive)
+        .with_timezone(&tz)
+        .to_rfc3339()
+}